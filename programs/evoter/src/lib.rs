@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*; // Import Anchor framework prelude for Solana program development
 // This provides essential types like Context, Account, Signer, and error handling macros
 // Using prelude is efficient as it imports commonly used items without verbose imports
+use anchor_spl::token::{Mint, TokenAccount}; // SPL token types for token-weighted voting
+// Mint describes the governance token, TokenAccount lets us read a voter's on-chain balance
+// Pulling these from anchor_spl keeps token validation idiomatic rather than hand-rolled
 
 declare_id!("At2NcESoMB48ULsY7XPQFHAdjR1B5kEzfkP2Hk7WKrfD"); // Declare the program's on-chain address
 // This ID is generated during deployment and must match the deployed program
@@ -19,62 +22,108 @@ pub mod evoter { // Main program module containing all voting logic
         question: String, // The poll question text
         options: Vec<String>, // Vector of voting options (flexible length)
     ) -> Result<()> { // Returns Result for error handling
-        // Input validation: Ensure poll meets minimum requirements
-        // Prevents invalid polls that could cause runtime errors or waste resources
-        require!(options.len() >= 2, VotingError::NotEnoughOptions); // Minimum 2 options for meaningful poll
-        require!(options.len() <= PollAccount::MAX_OPTIONS, VotingError::TooManyOptions); // Prevent excessive storage usage
-        require!(question.len() <= PollAccount::MAX_QUESTION_LEN, VotingError::QuestionTooLong); // Prevent spam/long questions
-        for opt in &options { // Validate each option individually
-            require!(opt.len() <= PollAccount::MAX_OPTION_LEN, VotingError::OptionTooLong); // Consistent option length limits
-            require!(opt.len() > 0, VotingError::EmptyOption); // Prevent empty options that confuse voters
-        }
-
-        let poll = &mut ctx.accounts.poll; // Mutable reference to poll account for initialization
-
-        // Initialize poll state with provided data
-        // Setting fields explicitly ensures all data is properly stored
-        poll.creator = ctx.accounts.creator.key(); // Store creator for authorization checks
-        poll.poll_id = poll_id; // Unique identifier for poll lookup
-        poll.question = question; // The actual question text
-        poll.options = options.clone(); // Copy options vector to account
-        poll.votes = vec![0u64; options.len()]; // Initialize vote counts to zero for each option
-        poll.is_active = true; // New polls start active to accept votes
-        poll.bump = ctx.bumps.poll; // Store bump for PDA recreation (security best practice)
-
-        // Emit event for off-chain indexing and monitoring
-        // Events provide transparency and enable external services to track poll creation
-        emit!(PollCreated {
-            poll: poll.key(), // Poll account address
-            creator: poll.creator, // Who created the poll
-            poll_id, // Poll identifier
-            option_count: poll.options.len() as u8, // Number of options
-            ts: Clock::get()?.unix_timestamp, // Creation timestamp for ordering
-        });
+        // Preserve the original three-argument surface by wrapping the inputs in a
+        // default config: plain one-person-one-vote, no time limit, no allowlist.
+        // Integrators who only need a basic poll keep calling this unchanged.
+        let config = CreatePollConfig {
+            question, // Poll question carried through verbatim
+            options, // Options carried through verbatim
+            ..CreatePollConfig::default() // Leave the advanced settings at their defaults
+        };
+        process_create_poll(ctx, poll_id, config) // Delegate to the shared initializer
+    }
 
-        Ok(()) // Return success - poll created successfully
+    // create_poll_v2 instruction: Full-featured poll creation via a single config struct
+    // Bundles the optional settings (duration, weighting, gating) behind one stable arg
+    // so new features land as config fields instead of ever-growing positional params
+    pub fn create_poll_v2(
+        ctx: Context<CreatePoll>, // Same accounts as create_poll
+        poll_id: u64, // Unique identifier for the poll
+        config: CreatePollConfig, // Bundled question, options, and optional settings
+    ) -> Result<()> {
+        process_create_poll(ctx, poll_id, config) // Delegate to the shared initializer
     }
 
     // vote_poll instruction: Records a vote for a specific poll option
     // Uses PDA-based vote records to prevent double voting
     // Atomic operation ensures vote is recorded and count updated together
-    pub fn vote_poll(ctx: Context<VotePoll>, option_index: u8) -> Result<()> {
+    pub fn vote_poll(
+        ctx: Context<VotePoll>, // Context provides accounts for this vote
+        option_index: u8, // Which option the voter is choosing
+        proof: Vec<[u8; 32]>, // Merkle proof of eligibility (empty for open polls)
+    ) -> Result<()> {
         let poll = &mut ctx.accounts.poll; // Mutable reference to update vote counts
         let voter = &ctx.accounts.voter; // Reference to voter account for event emission
 
+        // Gated polls carry a Merkle root; the voter must prove membership before voting
+        // Open polls leave the root unset, so the proof is ignored entirely
+        if let Some(root) = poll.allowlist_root {
+            require!(
+                verify_allowlist(&root, &proof, voter.key()), // Fold the proof up to the stored root
+                VotingError::NotEligible // Reject voters whose proof doesn't reconstruct the root
+            );
+        }
+
         // Validate poll is still accepting votes
         // Prevents voting on closed polls which could manipulate results
         require!(poll.is_active, VotingError::PollClosed);
 
+        // Reject votes once the poll's window has elapsed, even if nobody has run the
+        // expiry crank yet — the clock is the source of truth, not the is_active flag
+        require!(
+            Clock::get()?.unix_timestamp < poll.end_ts, // Current time must be within the voting window
+            VotingError::PollExpired // Clear feedback that the poll's time is up
+        );
+
         // Validate option index is within bounds
         // Prevents out-of-bounds access that could cause runtime panics
         require!((option_index as usize) < poll.options.len(), VotingError::InvalidOption);
 
+        // Resolve how much this vote counts for based on the poll's weighting rule
+        // Plain polls count one; token-weighted polls count the voter's live token balance
+        let weight = match poll.weight_mode {
+            WeightMode::OnePersonOneVote => 1, // Classic democratic tally: every signer counts equally
+            WeightMode::TokenWeighted => {
+                // The poll must carry a configured mint; creation enforces this, but we
+                // re-assert it here so weight can never be derived without a bound token.
+                let configured_mint = poll.vote_mint.ok_or(VotingError::MissingVoteMint)?; // Expected governance mint
+
+                // Token-weighted polls require the voter's associated token account for the mint
+                // Missing accounts mean the client didn't supply the data needed to weigh the vote
+                let token_account = ctx
+                    .accounts
+                    .voter_token_account
+                    .as_ref() // Borrow the optional account without moving it
+                    .ok_or(VotingError::MissingTokenAccount)?; // Reject if the client omitted it
+
+                // Bind the balance to the *correct* governance token in code, not merely via a
+                // constraint on an optional `vote_mint` that is skipped when absent. Without this
+                // a voter could omit `vote_mint` and weigh a balance from an unrelated mint.
+                require!(
+                    configured_mint == token_account.mint, // Token account must track the poll's mint
+                    VotingError::WrongVoteMint // Reject balances from any other token
+                );
+                // The token account must also belong to the signing voter, so one wallet can't
+                // borrow another holder's balance to inflate its own voting power.
+                require!(
+                    token_account.owner == voter.key(), // Authority must be the voter casting the vote
+                    VotingError::WrongVoteMint // Reuse the binding error for a mismatched account
+                );
+
+                // Reading `amount` gives the voter's on-chain holdings at vote time
+                let balance = token_account.amount; // Balance snapshot drives voting power
+                require!(balance > 0, VotingError::ZeroVotingPower); // A holder of none cannot sway the poll
+                balance // Voting power scales directly with token holdings
+            }
+        };
+
         // Initialize vote record fields (Anchor handles account creation with init constraint)
         // VoteRecord PDA ensures one vote per user per poll
         let vote_record = &mut ctx.accounts.vote_record;
         vote_record.voter = ctx.accounts.voter.key(); // Store voter identity
         vote_record.poll = poll.key(); // Link to specific poll
         vote_record.option_index = option_index; // Record chosen option
+        vote_record.weight = weight; // Persist applied weight so tallies stay auditable
         vote_record.bump = ctx.bumps.vote_record; // Store bump for security
 
         // Create vote record (init in account validation) â€” prevents double voting
@@ -82,7 +131,7 @@ pub mod evoter { // Main program module containing all voting logic
         let idx = option_index as usize;
         poll.votes[idx] = poll
             .votes[idx]
-            .checked_add(1) // Use checked_add to prevent overflow
+            .checked_add(weight) // Add the resolved weight (1 or token balance) with overflow protection
             .ok_or(VotingError::VoteOverflow)?; // Handle theoretical overflow gracefully
 
         // Emit vote event for transparency and external tracking
@@ -91,6 +140,7 @@ pub mod evoter { // Main program module containing all voting logic
             poll: poll.key(), // Poll being voted on
             voter: voter.key(), // Who voted
             option_index, // Which option was chosen
+            weight, // Applied voting weight so indexers can reconstruct tallies
             ts: Clock::get()?.unix_timestamp, // Vote timestamp
         });
 
@@ -126,6 +176,199 @@ pub mod evoter { // Main program module containing all voting logic
 
         Ok(()) // Poll closed successfully
     }
+
+    // expire_poll instruction: Permissionlessly finalizes a poll whose window has passed
+    // Unlike close_poll (creator-only), anyone may crank this once end_ts is reached
+    // Lets off-chain schedulers reliably close polls without holding the creator's key
+    pub fn expire_poll(ctx: Context<ExpirePoll>) -> Result<()> {
+        let poll = &mut ctx.accounts.poll; // Mutable reference to flip the active flag
+
+        // Only already-active polls can be expired; double-cranking is a no-op worth rejecting
+        // Mirrors close_poll's guard so the lifecycle stays consistent across both paths
+        require!(poll.is_active, VotingError::PollAlreadyClosed);
+
+        // The poll's deadline must have passed before anyone can finalize it
+        // This is what makes the crank safe to expose to arbitrary signers
+        require!(
+            Clock::get()?.unix_timestamp >= poll.end_ts, // Deadline reached or exceeded
+            VotingError::PollNotExpired // Too early to expire a still-open poll
+        );
+
+        // Deactivate the poll exactly as close_poll does, so downstream consumers
+        // don't need to distinguish how a poll reached its final state
+        poll.is_active = false;
+
+        // Reuse PollClosed so indexers treat creator-close and crank-expire uniformly
+        // The cranker isn't the creator, so we report the stored creator, not the signer
+        emit!(PollClosed {
+            poll: poll.key(), // Poll being finalized
+            creator: poll.creator, // Original creator (not the permissionless cranker)
+            ts: Clock::get()?.unix_timestamp, // Finalization timestamp
+        });
+
+        Ok(()) // Poll expired successfully
+    }
+
+    // change_vote instruction: Lets a voter revise their choice while a poll is open
+    // Loads the existing VoteRecord (no init) and moves the voter's weight between options
+    // Mirrors how real poll systems allow participants to update answers before closing
+    pub fn change_vote(ctx: Context<ChangeVote>, new_option_index: u8) -> Result<()> {
+        let poll = &mut ctx.accounts.poll; // Mutable reference to shift vote counts
+        let voter = &ctx.accounts.voter; // Reference to voter for event emission
+
+        // Only open polls accept vote changes, same gate as casting a fresh vote
+        require!(poll.is_active, VotingError::PollClosed);
+
+        // Honour the poll's deadline here too, so a closed window can't be worked
+        // around by "changing" a vote after expiry
+        require!(
+            Clock::get()?.unix_timestamp < poll.end_ts, // Must still be within the voting window
+            VotingError::PollExpired // Deadline passed; no further changes allowed
+        );
+
+        // Validate the new option index is within bounds before touching any counts
+        require!((new_option_index as usize) < poll.options.len(), VotingError::InvalidOption);
+
+        let vote_record = &mut ctx.accounts.vote_record; // Existing record to update
+        let old_option_index = vote_record.option_index; // Previously chosen option
+
+        // Changing to the same option is a no-op and almost always a client mistake
+        require!(new_option_index != old_option_index, VotingError::SameOption);
+
+        // Move the voter's stored weight off the old option and onto the new one
+        // Using the recorded weight keeps token-weighted tallies correct, not just counts
+        let weight = vote_record.weight; // Voting power originally applied
+        let old_idx = old_option_index as usize; // Index to decrement
+        let new_idx = new_option_index as usize; // Index to increment
+        poll.votes[old_idx] = poll
+            .votes[old_idx]
+            .checked_sub(weight) // Remove the weight from the old option
+            .ok_or(VotingError::VoteOverflow)?; // Guard against underflow (should never happen)
+        poll.votes[new_idx] = poll
+            .votes[new_idx]
+            .checked_add(weight) // Add the weight to the new option
+            .ok_or(VotingError::VoteOverflow)?; // Guard against overflow
+
+        // Persist the new choice so a repeat change compares against the latest option
+        vote_record.option_index = new_option_index;
+
+        // Emit the transition so indexers can track how a voter revised their answer
+        emit!(VoteChanged {
+            poll: poll.key(), // Poll whose tally changed
+            voter: voter.key(), // Who changed their vote
+            old_option_index, // Option the weight moved from
+            new_option_index, // Option the weight moved to
+            weight, // Voting power that was moved
+            ts: Clock::get()?.unix_timestamp, // Change timestamp
+        });
+
+        Ok(()) // Vote changed successfully
+    }
+}
+
+// -------------------- Helpers --------------------
+
+/// Shared poll-initialization routine backing both `create_poll` and
+/// `create_poll_v2`. Centralizing the logic here means every entry point
+/// validates and stamps polls identically, no matter how the arguments arrive.
+fn process_create_poll(
+    ctx: Context<CreatePoll>, // Accounts for the poll being created
+    poll_id: u64, // Unique identifier for the poll
+    config: CreatePollConfig, // Bundled question, options, and optional settings
+) -> Result<()> {
+    let CreatePollConfig {
+        question, // Poll question text
+        options, // Voting options
+        duration_secs, // Lifetime in seconds (0 = no limit)
+        weight_mode, // Tallying rule
+        vote_mint, // Governance mint for weighted polls
+        allowlist_root, // Merkle root for gated polls
+    } = config; // Destructure once so the body reads like the old positional form
+
+    // Input validation: Ensure poll meets minimum requirements
+    // Prevents invalid polls that could cause runtime errors or waste resources
+    require!(options.len() >= 2, VotingError::NotEnoughOptions); // Minimum 2 options for meaningful poll
+    require!(options.len() <= PollAccount::MAX_OPTIONS, VotingError::TooManyOptions); // Prevent excessive storage usage
+    require!(question.len() <= PollAccount::MAX_QUESTION_LEN, VotingError::QuestionTooLong); // Prevent spam/long questions
+    for opt in &options { // Validate each option individually
+        require!(opt.len() <= PollAccount::MAX_OPTION_LEN, VotingError::OptionTooLong); // Consistent option length limits
+        require!(opt.len() > 0, VotingError::EmptyOption); // Prevent empty options that confuse voters
+    }
+
+    // Token-weighted polls are meaningless without a mint to measure balances against
+    // Requiring the mint up front stops a poll from being created in an unusable state
+    if weight_mode == WeightMode::TokenWeighted {
+        require!(vote_mint.is_some(), VotingError::MissingVoteMint); // Enforce a mint for weighted tallies
+    }
+
+    // A negative duration is nonsensical for a poll lifetime and hints at a client bug
+    // Zero is allowed and means "no time limit" (see end_ts handling below)
+    require!(duration_secs >= 0, VotingError::InvalidDuration); // Reject negative lifetimes outright
+
+    // Stamp the poll's lifetime from the on-chain clock so expiry is tamper-resistant
+    // end_ts of i64::MAX encodes an open-ended poll that only close_poll can finalize
+    let start_ts = Clock::get()?.unix_timestamp; // Poll's creation time anchors its window
+    let end_ts = if duration_secs == 0 {
+        i64::MAX // No limit: poll stays open until the creator closes it
+    } else {
+        start_ts
+            .checked_add(duration_secs) // Offset start by the requested lifetime
+            .ok_or(VotingError::InvalidDuration)? // Guard against timestamp overflow
+    };
+
+    let poll = &mut ctx.accounts.poll; // Mutable reference to poll account for initialization
+
+    // Initialize poll state with provided data
+    // Setting fields explicitly ensures all data is properly stored
+    poll.creator = ctx.accounts.creator.key(); // Store creator for authorization checks
+    poll.poll_id = poll_id; // Unique identifier for poll lookup
+    poll.question = question; // The actual question text
+    poll.options = options.clone(); // Copy options vector to account
+    poll.votes = vec![0u64; options.len()]; // Initialize vote counts to zero for each option
+    poll.is_active = true; // New polls start active to accept votes
+    poll.weight_mode = weight_mode; // Remember which tallying rule governs this poll
+    poll.vote_mint = vote_mint; // Governance mint used to read voter balances (None for plain polls)
+    poll.start_ts = start_ts; // When voting opened
+    poll.end_ts = end_ts; // When voting automatically closes (i64::MAX if open-ended)
+    poll.allowlist_root = allowlist_root; // Merkle root gating eligible voters (None = open to all)
+    poll.bump = ctx.bumps.poll; // Store bump for PDA recreation (security best practice)
+
+    // Emit event for off-chain indexing and monitoring
+    // Events provide transparency and enable external services to track poll creation
+    emit!(PollCreated {
+        poll: poll.key(), // Poll account address
+        creator: poll.creator, // Who created the poll
+        poll_id, // Poll identifier
+        option_count: poll.options.len() as u8, // Number of options
+        ts: Clock::get()?.unix_timestamp, // Creation timestamp for ordering
+    });
+
+    Ok(()) // Return success - poll created successfully
+}
+
+/// Verify that `voter` belongs to the allowlist committed to by `root`.
+///
+/// The leaf is `keccak256(voter)`, and each proof element is a sibling hash.
+/// Pairs are hashed in sorted byte order so we never have to store left/right
+/// position bits — the same convention OpenZeppelin's Merkle proofs use. After
+/// folding every sibling in, the computed hash must equal the stored root.
+fn verify_allowlist(root: &[u8; 32], proof: &[[u8; 32]], voter: Pubkey) -> bool {
+    use anchor_lang::solana_program::keccak; // keccak256 lives in the Solana program crate
+
+    // Start from the leaf commitment to this voter's pubkey
+    let mut computed = keccak::hashv(&[voter.as_ref()]).0; // leaf = keccak256(voter)
+
+    // Fold each sibling into the running hash, ordering the pair by bytes first
+    for sibling in proof {
+        computed = if computed <= *sibling {
+            keccak::hashv(&[&computed, sibling]).0 // current is the smaller half
+        } else {
+            keccak::hashv(&[sibling, &computed]).0 // sibling is the smaller half
+        };
+    }
+
+    // Membership holds iff the folded hash reproduces the committed root
+    &computed == root
 }
 
 // -------------------- Accounts --------------------
@@ -178,6 +421,23 @@ pub struct VotePoll<'info> {
     // Unchecked account since we only use it for validation
     pub creator: UncheckedAccount<'info>, // Poll creator for validation
 
+    /// Governance mint, supplied only for token-weighted polls.
+    /// Constrained to match the mint the poll was created with so votes
+    /// can't be weighted against an unrelated token.
+    #[account(
+        constraint = poll.vote_mint == Some(vote_mint.key()) @ VotingError::WrongVoteMint // Must match the poll's configured mint
+    )]
+    pub vote_mint: Option<Account<'info, Mint>>, // Present for TokenWeighted polls, omitted otherwise
+
+    /// Voter's associated token account for `vote_mint`, supplied only for
+    /// token-weighted polls. `token::mint`/`token::authority` ensure the
+    /// account truly belongs to this voter and tracks the governance mint.
+    #[account(
+        token::mint = vote_mint, // Token account must be for the governance mint
+        token::authority = voter // Token account must be owned by the voter casting the vote
+    )]
+    pub voter_token_account: Option<Account<'info, TokenAccount>>, // Balance source for weighted votes
+
     pub system_program: Program<'info, System>, // For account creation
     pub rent: Sysvar<'info, Rent>, // Rent sysvar (Anchor handles validation)
 }
@@ -191,6 +451,36 @@ pub struct ClosePoll<'info> {
     pub creator: Signer<'info>, // Must be poll creator
 }
 
+#[derive(Accounts)] // Account validation for expire_poll instruction
+pub struct ExpirePoll<'info> {
+    #[account(mut)] // Mutable to flip the is_active flag once expired
+    pub poll: Account<'info, PollAccount>, // Poll whose window has elapsed
+
+    // Any signer may crank expiry; no creator check, hence a plain Signer.
+    // The on-chain deadline is what authorizes the action, not identity.
+    pub cranker: Signer<'info>, // Permissionless caller finalizing the poll
+}
+
+#[derive(Accounts)] // Account validation for change_vote instruction
+pub struct ChangeVote<'info> {
+    #[account(mut)] // Mutable to shift vote counts between options
+    pub poll: Account<'info, PollAccount>, // Poll whose tally is being adjusted
+
+    /// Existing vote record for this voter/poll pair — loaded, never initialized.
+    /// The seeds and has_one checks ensure the record really belongs to this
+    /// voter and poll, preventing one voter from editing another's vote.
+    #[account(
+        mut, // Mutable to record the new option choice
+        seeds = [b"vote", poll.key().as_ref(), voter.key().as_ref()], // Same seeds used at vote time
+        bump = vote_record.bump, // Reuse the stored bump rather than recomputing
+        has_one = poll, // Record must reference this poll
+        has_one = voter, // Record must belong to the signing voter
+    )]
+    pub vote_record: Account<'info, VoteRecord>, // Vote to be revised
+
+    pub voter: Signer<'info>, // Must be the original voter
+}
+
 // -------------------- Data Structures --------------------
 
 #[account] // Macro makes this a Solana account that can be serialized/deserialized
@@ -201,9 +491,44 @@ pub struct PollAccount { // Main poll data structure stored on-chain
     pub options: Vec<String>, // Vector of voting options
     pub votes: Vec<u64>, // Vote counts corresponding to options
     pub is_active: bool, // Whether poll accepts new votes
+    pub weight_mode: WeightMode, // Tallying rule applied to every vote on this poll
+    pub vote_mint: Option<Pubkey>, // Governance mint for token-weighted polls (None otherwise)
+    pub start_ts: i64, // Unix timestamp when voting opened
+    pub end_ts: i64, // Unix timestamp when voting auto-closes (i64::MAX if open-ended)
+    pub allowlist_root: Option<[u8; 32]>, // Merkle root of eligible voters (None = open poll)
     pub bump: u8, // PDA bump for address recreation
 }
 
+/// Determines how much each vote contributes to a poll's tally.
+/// Kept as a small on-chain enum so the rule travels with the poll and
+/// both the handler and off-chain indexers read it the same way.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)] // Serializable + comparable for on-chain storage
+pub enum WeightMode { // Voting-power model for a poll
+    OnePersonOneVote, // Every signer adds exactly one vote (classic democratic tally)
+    TokenWeighted, // Each vote adds the voter's governance-token balance
+}
+
+impl Default for WeightMode { // Default keeps plain polls one-person-one-vote
+    fn default() -> Self {
+        WeightMode::OnePersonOneVote // Safest, least-surprising tallying rule
+    }
+}
+
+/// Bundled inputs for creating a poll, passed to `create_poll_v2`.
+///
+/// Collapsing the growing list of optional settings into one struct gives
+/// integrators a single, stable instruction surface: new features arrive as
+/// new fields (with sensible defaults) rather than extra positional arguments.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)] // Anchor-serializable instruction input
+pub struct CreatePollConfig { // Full-featured poll creation parameters
+    pub question: String, // The poll question text
+    pub options: Vec<String>, // Voting options (2..=MAX_OPTIONS)
+    pub duration_secs: i64, // Poll lifetime in seconds (0 = no time limit)
+    pub weight_mode: WeightMode, // Tallying rule: one-person-one-vote vs token-weighted
+    pub vote_mint: Option<Pubkey>, // Governance mint for token-weighted polls
+    pub allowlist_root: Option<[u8; 32]>, // Merkle root gating eligible voters (None = open)
+}
+
 /* Conservative constants */ // Fixed limits prevent abuse and ensure predictable costs
 impl PollAccount { // Implementation block for PollAccount
     pub const MAX_OPTIONS: usize = 10; // Reasonable limit prevents storage bloat
@@ -228,6 +553,14 @@ impl PollAccount { // Implementation block for PollAccount
         size += 4 + (Self::MAX_OPTIONS * 8);
         // is_active: 1 byte for boolean
         size += 1;
+        // weight_mode: 1 byte for the enum discriminant
+        size += 1;
+        // vote_mint: Option<Pubkey> = 1 byte tag + 32 bytes pubkey
+        size += 1 + 32;
+        // start_ts + end_ts: two i64 timestamps at 8 bytes each
+        size += 8 + 8;
+        // allowlist_root: Option<[u8; 32]> = 1 byte tag + 32 bytes root
+        size += 1 + 32;
         // bump: 1 byte for PDA bump
         size += 1;
         // padding (safety): Extra space for future fields or alignment
@@ -241,13 +574,14 @@ pub struct VoteRecord { // Tracks individual votes to prevent double voting
     pub voter: Pubkey, // Who cast the vote
     pub poll: Pubkey, // Which poll was voted on
     pub option_index: u8, // Which option was chosen (0-based index)
+    pub weight: u64, // Voting power applied (1, or token balance for weighted polls)
     pub bump: u8, // PDA bump for security
 }
 
 impl VoteRecord { // Implementation for VoteRecord
-    // fixed size: discriminator(8) + voter(32) + poll(32) + option_index(1) + bump(1)
+    // fixed size: discriminator(8) + voter(32) + poll(32) + option_index(1) + weight(8) + bump(1)
     // Pre-calculated size ensures consistent account allocation
-    pub const SIZE: usize = 8 + 32 + 32 + 1 + 1; // 74 bytes total
+    pub const SIZE: usize = 8 + 32 + 32 + 1 + 8 + 1; // 82 bytes total
 }
 
 // -------------------- Events --------------------
@@ -266,9 +600,20 @@ pub struct Voted { // Event emitted when a vote is cast
     pub poll: Pubkey, // Poll that received the vote
     pub voter: Pubkey, // Who cast the vote
     pub option_index: u8, // Which option was chosen
+    pub weight: u64, // Voting power applied to this vote (1 or token balance)
     pub ts: i64, // Unix timestamp of vote
 }
 
+#[event] // Event for a revised vote
+pub struct VoteChanged { // Event emitted when a voter changes their choice
+    pub poll: Pubkey, // Poll whose tally changed
+    pub voter: Pubkey, // Who changed their vote
+    pub old_option_index: u8, // Option the weight moved from
+    pub new_option_index: u8, // Option the weight moved to
+    pub weight: u64, // Voting power that was moved
+    pub ts: i64, // Unix timestamp of the change
+}
+
 #[event] // Event for poll closure
 pub struct PollClosed { // Event emitted when a poll is closed
     pub poll: Pubkey, // Poll that was closed
@@ -301,4 +646,22 @@ pub enum VotingError { // Custom error enum for voting-specific errors
     VoteOverflow, // Theoretical overflow protection
     #[msg("User has already voted on this poll.")]
     AlreadyVoted, // Double-voting prevention feedback
+    #[msg("Token-weighted polls require a vote mint.")]
+    MissingVoteMint, // Weighted poll created without a governance mint
+    #[msg("Wrong vote mint for this poll.")]
+    WrongVoteMint, // Supplied mint does not match the poll's configured mint
+    #[msg("Token account required for token-weighted voting.")]
+    MissingTokenAccount, // Weighted vote cast without the voter's token account
+    #[msg("Voter holds no tokens and has zero voting power.")]
+    ZeroVotingPower, // Token-weighted voter with an empty balance
+    #[msg("Poll duration must be non-negative.")]
+    InvalidDuration, // Negative or overflowing poll lifetime
+    #[msg("Poll has expired and no longer accepts votes.")]
+    PollExpired, // Vote attempted after the poll's end_ts
+    #[msg("Poll has not yet reached its expiry time.")]
+    PollNotExpired, // Expiry crank called before end_ts
+    #[msg("Voter is not on this poll's allowlist.")]
+    NotEligible, // Merkle proof failed to reconstruct the allowlist root
+    #[msg("New option is the same as the current vote.")]
+    SameOption, // change_vote called without actually changing the choice
 }